@@ -5,10 +5,16 @@
 use proc_macro2::Ident;
 use quote::ToTokens;
 use syn::{punctuated::Punctuated, Token};
-use uniffi_meta::{FnParamMetadata, Type};
+use uniffi_meta::{FnParamMetadata, PassingStyle, ReturnTypeMetadata, Type};
+
+/// Name attributed to the error type of a `Result<T>` whose error has been
+/// elided behind a type alias. The name is fixed: no attribute or option
+/// changes it.
+const DEFAULT_ERROR_NAME: &str = "Error";
 
 pub(super) fn fn_param_metadata(
     params: &Punctuated<syn::FnArg, Token![,]>,
+    mod_path: &[String],
 ) -> syn::Result<Vec<FnParamMetadata>> {
     params
         .iter()
@@ -25,19 +31,145 @@ pub(super) fn fn_param_metadata(
                 }
             };
 
-            Some(convert_type(ty).map(|ty| FnParamMetadata { name, ty }))
+            let passing_style = passing_style(ty);
+            Some(convert_type(ty, mod_path).map(|ty| FnParamMetadata {
+                name,
+                ty,
+                passing_style,
+            }))
         })
         .collect()
 }
 
-pub(super) fn return_type_metadata(ty: &syn::ReturnType) -> syn::Result<Option<Type>> {
+/// How a parameter wants to be handed to the Rust call. Receivers never reach
+/// here (they stay on the `FnArg::Receiver` branch), so a `&T`/`&mut T`
+/// parameter simply records that scaffolding may pass a borrow instead of an
+/// owned, cloned value.
+fn passing_style(ty: &syn::Type) -> PassingStyle {
+    match strip_groups(ty) {
+        syn::Type::Reference(r) => {
+            if r.mutability.is_some() {
+                PassingStyle::ByMutRef
+            } else {
+                PassingStyle::ByRef
+            }
+        }
+        _ => PassingStyle::ByValue,
+    }
+}
+
+pub(super) fn return_type_metadata(
+    ty: &syn::ReturnType,
+    mod_path: &[String],
+) -> syn::Result<ReturnTypeMetadata> {
     Ok(match ty {
-        syn::ReturnType::Default => None,
-        syn::ReturnType::Type(_, ty) => Some(convert_type(ty)?),
+        syn::ReturnType::Default => ReturnTypeMetadata {
+            ty: None,
+            throws: None,
+        },
+        syn::ReturnType::Type(_, ty) => convert_return_type(ty, mod_path)?,
     })
 }
 
-pub(crate) fn convert_type(ty: &syn::Type) -> syn::Result<Type> {
+fn convert_return_type(ty: &syn::Type, mod_path: &[String]) -> syn::Result<ReturnTypeMetadata> {
+    // A `Result<..>` in return position makes the function fallible; peel it
+    // apart into the success and error types so scaffolding can emit the
+    // error-lowering path (mirrors cxx's `kw::Result` handling).
+    if let Ok(type_path) = type_as_type_path(ty) {
+        if let Some(seg) = type_path.path.segments.last() {
+            if seg.ident == "Result" {
+                if let syn::PathArguments::AngleBracketed(a) = &seg.arguments {
+                    return convert_result_type(a, mod_path);
+                }
+            }
+        }
+    }
+
+    // An explicit `-> ()` is just the unit tuple, which carries no value; treat
+    // it exactly like a missing return type.
+    let ty = if is_unit_tuple(ty) {
+        None
+    } else {
+        Some(convert_type(ty, mod_path)?)
+    };
+    Ok(ReturnTypeMetadata { ty, throws: None })
+}
+
+fn convert_result_type(
+    a: &syn::AngleBracketedGenericArguments,
+    mod_path: &[String],
+) -> syn::Result<ReturnTypeMetadata> {
+    let mut it = a.args.iter();
+    let ok = it
+        .next()
+        .ok_or_else(|| syn::Error::new_spanned(a, "`Result` requires a success type"))?;
+    let ok = arg_as_type(ok)?;
+
+    // `Result<(), E>` carries no success value but still throws.
+    let ty = if is_unit_tuple(ok) {
+        None
+    } else {
+        Some(convert_type(ok, mod_path)?)
+    };
+
+    // An elided error (`Result<T>` behind an alias) falls back to the fixed
+    // `DEFAULT_ERROR_NAME`.
+    let throws = Some(match it.next() {
+        Some(err) => error_name(arg_as_type(err)?)?,
+        None => DEFAULT_ERROR_NAME.to_string(),
+    });
+
+    if it.next().is_some() {
+        return Err(syn::Error::new_spanned(
+            a,
+            "`Result` with more than two generics is not currently supported by uniffi::export",
+        ));
+    }
+
+    Ok(ReturnTypeMetadata { ty, throws })
+}
+
+fn error_name(ty: &syn::Type) -> syn::Result<String> {
+    Ok(type_as_type_path(ty)?
+        .path
+        .segments
+        .last()
+        .ok_or_else(|| type_not_supported(ty))?
+        .ident
+        .to_string())
+}
+
+pub(crate) fn convert_type(ty: &syn::Type, mod_path: &[String]) -> syn::Result<Type> {
+    // Macro expansion (and plain redundant parens in hand-written source) can
+    // wrap any of the types below in `Paren`/`Group`; strip those once up
+    // front so e.g. `((u32, String))` or `(&[u8])` match the same branches as
+    // their unwrapped forms instead of falling through to "not supported".
+    let ty = strip_groups(ty);
+
+    if let syn::Type::Tuple(t) = ty {
+        return convert_tuple(t, mod_path);
+    }
+
+    if let syn::Type::Array(arr) = ty {
+        return convert_array(arr, mod_path);
+    }
+
+    // A `&T`/`&mut T` parameter converts as its referent; the passing style is
+    // recorded separately (see `passing_style`). Any lifetime is erased because
+    // the FFI boundary owns the buffer for the duration of the call.
+    if let syn::Type::Reference(r) = ty {
+        // `&[T]` is a borrowed slice rather than a borrowed value: keep it as a
+        // distinct `Type::Slice` so generators can lower `&[u8]` straight from
+        // the caller's contiguous buffer (zero-copy) instead of round-tripping
+        // through a `Vec<u8>`, and so the borrow does not outlive the call.
+        if let syn::Type::Slice(slice) = strip_groups(&r.elem) {
+            return Ok(Type::Slice {
+                inner_type: convert_type(&slice.elem, mod_path)?.into(),
+            });
+        }
+        return convert_type(&r.elem, mod_path);
+    }
+
     let type_path = type_as_type_path(ty)?;
 
     if type_path.qself.is_some() {
@@ -47,38 +179,147 @@ pub(crate) fn convert_type(ty: &syn::Type) -> syn::Result<Type> {
         ));
     }
 
-    if type_path.path.segments.len() > 1 {
-        return Err(syn::Error::new_spanned(
-            type_path,
-            "qualified paths in types are not currently supported by uniffi::export",
-        ));
+    // A single-segment path (`String`, `Vec<T>`, ...) takes the fast path. For a
+    // qualified path like `std::collections::HashMap<..>` we resolve against the
+    // *last* segment, provided the leading segment names a namespace we know how
+    // to look through; `Arc<crate::db::Connection>`-style object paths keep their
+    // full qualification and are handled in `convert_generic_type1`.
+    let seg = match type_path.path.segments.len() {
+        1 => type_path.path.segments.first().unwrap(),
+        _ => {
+            check_known_namespace(type_path)?;
+            type_path.path.segments.last().unwrap()
+        }
+    };
+
+    match &seg.arguments {
+        syn::PathArguments::None => convert_bare_type_name(&seg.ident),
+        syn::PathArguments::AngleBracketed(a) => convert_generic_type(&seg.ident, a, mod_path),
+        syn::PathArguments::Parenthesized(_) => Err(type_not_supported(type_path)),
     }
+}
 
-    match &type_path.path.segments.first() {
-        Some(seg) => match &seg.arguments {
-            syn::PathArguments::None => convert_bare_type_name(&seg.ident),
-            syn::PathArguments::AngleBracketed(a) => convert_generic_type(&seg.ident, a),
-            syn::PathArguments::Parenthesized(_) => Err(type_not_supported(type_path)),
-        },
-        None => Err(syn::Error::new_spanned(
-            type_path,
-            "unreachable: TypePath must have non-empty segments",
-        )),
+/// Submodules we are willing to look through between a known leading
+/// namespace and the final (builtin/container) segment, e.g. the `collections`
+/// in `std::collections::HashMap` or the `vec` in `alloc::vec::Vec`.
+const KNOWN_SUBMODULES: &[&str] = &[
+    "collections",
+    "string",
+    "vec",
+    "boxed",
+    "sync",
+    "option",
+    "result",
+    "borrow",
+    "rc",
+    "cell",
+];
+
+/// Namespaces we are willing to look through when resolving a builtin or
+/// container type written with a qualified path (e.g. the `std::collections`
+/// in `std::collections::HashMap`).
+fn check_known_namespace(type_path: &syn::TypePath) -> syn::Result<()> {
+    let segments = &type_path.path.segments;
+    let leading = segments.first().unwrap().ident.to_string();
+    match leading.as_str() {
+        "std" | "alloc" | "core" | "collections" => {}
+        _ => {
+            return Err(syn::Error::new_spanned(
+                type_path,
+                "qualified paths in types must start with a known namespace \
+                 (`std`, `alloc`, `core`, `collections`) for uniffi::export",
+            ))
+        }
+    }
+
+    // Everything between the leading namespace and the final segment must
+    // also be a recognized submodule; a typo'd or garbage middle segment
+    // (`std::bogus::HashMap`) should be a clear macro-time error rather than
+    // silently falling through to whatever the last segment happens to name.
+    let middle_len = segments.len().saturating_sub(2);
+    for seg in segments.iter().skip(1).take(middle_len) {
+        let name = seg.ident.to_string();
+        if !KNOWN_SUBMODULES.contains(&name.as_str()) {
+            return Err(syn::Error::new_spanned(
+                type_path,
+                format!(
+                    "`{name}` is not a recognized submodule in a qualified type path \
+                     for uniffi::export"
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn convert_tuple(t: &syn::TypeTuple, mod_path: &[String]) -> syn::Result<Type> {
+    // Note that `(T)` is a `syn::Type::Paren`, not a one-element tuple; only a
+    // trailing comma in `elems` makes `(T,)` a genuine tuple, and syn preserves
+    // that distinction for us, so we never collapse a parenthesized type here.
+    let inner_types = t
+        .elems
+        .iter()
+        .map(|ty| convert_type(ty, mod_path))
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(Type::Tuple { inner_types })
+}
+
+/// Look through `Group`/`Paren` wrappers (macro expansion inserts invisible
+/// groups) to reach the underlying type.
+fn strip_groups(ty: &syn::Type) -> &syn::Type {
+    match ty {
+        syn::Type::Group(g) => strip_groups(&g.elem),
+        syn::Type::Paren(p) => strip_groups(&p.elem),
+        _ => ty,
+    }
+}
+
+fn convert_array(arr: &syn::TypeArray, mod_path: &[String]) -> syn::Result<Type> {
+    // Only integer-literal lengths can be resolved at macro-expansion time;
+    // a const-generic or named-const length has no value we can read here.
+    let length = match &arr.len {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(int),
+            ..
+        }) => int.base10_parse()?,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &arr.len,
+                "array lengths must be integer literals; const-generic or named \
+                 lengths cannot be resolved by uniffi::export",
+            ))
+        }
+    };
+
+    Ok(Type::Array {
+        inner_type: convert_type(&arr.elem, mod_path)?.into(),
+        length,
+    })
+}
+
+fn is_unit_tuple(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Group(g) => is_unit_tuple(&g.elem),
+        syn::Type::Paren(p) => is_unit_tuple(&p.elem),
+        syn::Type::Tuple(t) => t.elems.is_empty(),
+        _ => false,
     }
 }
 
 fn convert_generic_type(
     ident: &Ident,
     a: &syn::AngleBracketedGenericArguments,
+    mod_path: &[String],
 ) -> syn::Result<Type> {
     let mut it = a.args.iter();
     match it.next() {
         // `u8<>` is a valid way to write `u8` in the type namespace, so why not?
         None => convert_bare_type_name(ident),
         Some(arg1) => match it.next() {
-            None => convert_generic_type1(ident, arg1),
+            None => convert_generic_type1(ident, arg1, mod_path),
             Some(arg2) => match it.next() {
-                None => convert_generic_type2(ident, arg1, arg2),
+                None => convert_generic_type2(ident, arg1, arg2, mod_path),
                 Some(_) => Err(syn::Error::new_spanned(
                     ident,
                     "types with more than two generics are not currently
@@ -107,21 +348,21 @@ fn convert_bare_type_name(ident: &Ident) -> syn::Result<Type> {
     }
 }
 
-fn convert_generic_type1(ident: &Ident, arg: &syn::GenericArgument) -> syn::Result<Type> {
+fn convert_generic_type1(
+    ident: &Ident,
+    arg: &syn::GenericArgument,
+    mod_path: &[String],
+) -> syn::Result<Type> {
     let arg = arg_as_type(arg)?;
     match ident.to_string().as_str() {
         "Arc" => Ok(Type::ArcObject {
-            object_name: type_as_type_path(arg)?
-                .path
-                .get_ident()
-                .ok_or_else(|| type_not_supported(arg))?
-                .to_string(),
+            object_name: object_name(arg, mod_path)?,
         }),
         "Option" => Ok(Type::Option {
-            inner_type: convert_type(arg)?.into(),
+            inner_type: convert_type(arg, mod_path)?.into(),
         }),
         "Vec" => Ok(Type::Vec {
-            inner_type: convert_type(arg)?.into(),
+            inner_type: convert_type(arg, mod_path)?.into(),
         }),
         _ => Err(type_not_supported(ident)),
     }
@@ -131,19 +372,65 @@ fn convert_generic_type2(
     ident: &Ident,
     arg1: &syn::GenericArgument,
     arg2: &syn::GenericArgument,
+    mod_path: &[String],
 ) -> syn::Result<Type> {
     let arg1 = arg_as_type(arg1)?;
     let arg2 = arg_as_type(arg2)?;
 
     match ident.to_string().as_str() {
         "HashMap" => Ok(Type::HashMap {
-            key_type: convert_type(arg1)?.into(),
-            value_type: convert_type(arg2)?.into(),
+            key_type: convert_type(arg1, mod_path)?.into(),
+            value_type: convert_type(arg2, mod_path)?.into(),
         }),
         _ => Err(type_not_supported(ident)),
     }
 }
 
+/// Resolve the object name an `Arc<..>` points at into a module-qualified
+/// identifier. An unqualified name (`Arc<Connection>`) is anchored at the
+/// current module path so it stays unambiguous across modules; an explicitly
+/// qualified path (`Arc<crate::db::Connection>`) keeps its own segments, with a
+/// leading crate-root marker dropped. A leading `self` is relative to the
+/// current module, so such a path is anchored at the module path too.
+fn object_name(arg: &syn::Type, mod_path: &[String]) -> syn::Result<String> {
+    let path = &type_as_type_path(arg)?.path;
+
+    // The final segment must be a bare identifier: `Arc<Vec<u8>>` or
+    // `Arc<some::Generic<T>>` are not object references, and letting the
+    // generic arguments through silently would misclassify them (e.g. as an
+    // object named `Vec`) instead of failing with a clear error here.
+    if !matches!(
+        path.segments.last().unwrap().arguments,
+        syn::PathArguments::None
+    ) {
+        return Err(type_not_supported(arg));
+    }
+
+    let mut segments: Vec<String> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+    let anchored = match segments.first().map(String::as_str) {
+        Some("crate") => {
+            segments.remove(0);
+            false
+        }
+        Some("self") => {
+            segments.remove(0);
+            true
+        }
+        _ => segments.len() == 1,
+    };
+    if segments.is_empty() {
+        return Err(type_not_supported(arg));
+    }
+
+    if anchored {
+        let mut qualified = mod_path.to_vec();
+        qualified.extend(segments);
+        Ok(qualified.join("::"))
+    } else {
+        Ok(segments.join("::"))
+    }
+}
+
 pub(super) fn type_as_type_path(ty: &syn::Type) -> syn::Result<&syn::TypePath> {
     match ty {
         syn::Type::Group(g) => type_as_type_path(&g.elem),
@@ -169,3 +456,97 @@ fn type_not_supported(ty: &impl ToTokens) -> syn::Error {
         "this type is not currently supported by uniffi::export in this position",
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    fn parse_type(tokens: proc_macro2::TokenStream) -> syn::Type {
+        syn::parse2(tokens).unwrap()
+    }
+
+    #[test]
+    fn tuple_type_converts_each_element() {
+        let ty = parse_type(quote! { (u32, String) });
+        let converted = convert_type(&ty, &[]).unwrap();
+        assert!(matches!(converted, Type::Tuple { ref inner_types } if inner_types.len() == 2));
+    }
+
+    #[test]
+    fn redundantly_parenthesized_tuple_still_converts() {
+        let ty = parse_type(quote! { ((u32, String)) });
+        let converted = convert_type(&ty, &[]).unwrap();
+        assert!(matches!(converted, Type::Tuple { .. }));
+    }
+
+    #[test]
+    fn redundantly_parenthesized_array_still_converts() {
+        let ty = parse_type(quote! { ([u8; 4]) });
+        let converted = convert_type(&ty, &[]).unwrap();
+        assert!(matches!(converted, Type::Array { length: 4, .. }));
+    }
+
+    #[test]
+    fn redundantly_parenthesized_reference_still_converts() {
+        let ty = parse_type(quote! { (&u32) });
+        let converted = convert_type(&ty, &[]).unwrap();
+        assert!(matches!(converted, Type::U32));
+    }
+
+    #[test]
+    fn result_return_type_splits_success_and_error() {
+        let ty: syn::ReturnType = syn::parse2(quote! { -> Result<String, MyError> }).unwrap();
+        let meta = return_type_metadata(&ty, &[]).unwrap();
+        assert!(matches!(meta.ty, Some(Type::String)));
+        assert_eq!(meta.throws.as_deref(), Some("MyError"));
+    }
+
+    #[test]
+    fn result_with_unit_success_still_throws() {
+        let ty: syn::ReturnType = syn::parse2(quote! { -> Result<(), MyError> }).unwrap();
+        let meta = return_type_metadata(&ty, &[]).unwrap();
+        assert!(meta.ty.is_none());
+        assert_eq!(meta.throws.as_deref(), Some("MyError"));
+    }
+
+    #[test]
+    fn qualified_path_resolves_against_last_segment() {
+        let ty = parse_type(quote! { std::collections::HashMap<String, u32> });
+        let converted = convert_type(&ty, &[]).unwrap();
+        assert!(matches!(converted, Type::HashMap { .. }));
+    }
+
+    #[test]
+    fn fixed_size_array_records_length() {
+        let ty = parse_type(quote! { [u8; 32] });
+        let converted = convert_type(&ty, &[]).unwrap();
+        assert!(matches!(converted, Type::Array { length: 32, .. }));
+    }
+
+    #[test]
+    fn by_ref_parameter_records_passing_style() {
+        let ty = parse_type(quote! { &Foo });
+        assert!(matches!(passing_style(&ty), PassingStyle::ByRef));
+    }
+
+    #[test]
+    fn by_mut_ref_parameter_records_passing_style() {
+        let ty = parse_type(quote! { &mut Foo });
+        assert!(matches!(passing_style(&ty), PassingStyle::ByMutRef));
+    }
+
+    #[test]
+    fn slice_of_u8_behind_reference_converts_to_slice_type() {
+        let ty = parse_type(quote! { &[u8] });
+        let converted = convert_type(&ty, &[]).unwrap();
+        assert!(matches!(converted, Type::Slice { .. }));
+    }
+
+    #[test]
+    fn redundantly_parenthesized_slice_reference_still_converts() {
+        let ty = parse_type(quote! { (&[u8]) });
+        let converted = convert_type(&ty, &[]).unwrap();
+        assert!(matches!(converted, Type::Slice { .. }));
+    }
+}